@@ -0,0 +1,107 @@
+use tauri::{ipc::Invoke, plugin::TauriPlugin, App, Builder, Wry};
+
+type SetupHook = Box<dyn FnOnce(&mut App<Wry>) -> Result<(), Box<dyn std::error::Error>> + Send>;
+type CommandHandler = Box<dyn Fn(Invoke<Wry>) -> bool + Send + Sync>;
+
+/// A `tauri::generate_handler!` output paired with the command names it
+/// covers, so [`AppBuilder`] can route an incoming `Invoke` to the one
+/// group that owns it instead of handing every group a chance to consume
+/// (and potentially reject) it.
+struct CommandGroup {
+    commands: &'static [&'static str],
+    handler: CommandHandler,
+}
+
+/// Composable wrapper around [`tauri::Builder`] that lets callers append
+/// setup hooks and command groups independently, then finalize them into a
+/// single `tauri::Builder` chain with one `.run()` call.
+///
+/// This lets the desktop and mobile entry points share one configuration
+/// path instead of each hardcoding their own builder chain.
+#[derive(Default)]
+pub struct AppBuilder {
+    setup_hooks: Vec<SetupHook>,
+    command_groups: Vec<CommandGroup>,
+    builder: Builder<Wry>,
+}
+
+impl AppBuilder {
+    pub fn new() -> Self {
+        Self {
+            setup_hooks: Vec::new(),
+            command_groups: Vec::new(),
+            builder: Builder::default(),
+        }
+    }
+
+    /// Registers a setup callback to run, in registration order, inside the
+    /// final builder's `setup` hook.
+    pub fn setup<F>(mut self, hook: F) -> Self
+    where
+        F: FnOnce(&mut App<Wry>) -> Result<(), Box<dyn std::error::Error>> + Send + 'static,
+    {
+        self.setup_hooks.push(Box::new(hook));
+        self
+    }
+
+    /// Registers a plugin with the underlying builder.
+    pub fn plugin(mut self, plugin: TauriPlugin<Wry>) -> Self {
+        self.builder = self.builder.plugin(plugin);
+        self
+    }
+
+    /// Merges a `tauri::generate_handler!` output, together with the list of
+    /// command names it handles (see [`command_group`]), into the app's
+    /// top-level invoke handler. Unlike routing commands through a plugin,
+    /// this keeps them callable as `invoke('command_name')` from the
+    /// frontend, exactly as if every command had been listed in one
+    /// `generate_handler!` call.
+    pub fn commands(mut self, commands: &'static [&'static str], handler: CommandHandler) -> Self {
+        self.command_groups.push(CommandGroup { commands, handler });
+        self
+    }
+
+    /// Finalizes the registered hooks and command groups into a single
+    /// `tauri::Builder` chain and runs it.
+    pub fn run(self, context: tauri::Context<Wry>) {
+        let setup_hooks = self.setup_hooks;
+        let command_groups = self.command_groups;
+
+        self.builder
+            .setup(move |app| {
+                for hook in setup_hooks {
+                    hook(app)?;
+                }
+                Ok(())
+            })
+            .invoke_handler(move |invoke| {
+                let command = invoke.message.command();
+                match command_groups
+                    .iter()
+                    .find(|group| group.commands.contains(&command))
+                {
+                    Some(group) => (group.handler)(invoke),
+                    None => {
+                        invoke
+                            .resolver
+                            .reject(format!("command {command} not found"));
+                        false
+                    }
+                }
+            })
+            .run(context)
+            .expect("error while running tauri application");
+    }
+}
+
+/// Pairs a `tauri::generate_handler!` output with the command names it
+/// covers, ready to pass to [`AppBuilder::commands`].
+pub fn command_group<F>(
+    commands: &'static [&'static str],
+    invoke_handler: F,
+) -> (&'static [&'static str], CommandHandler)
+where
+    F: Fn(Invoke<Wry>) -> bool + Send + Sync + 'static,
+{
+    (commands, Box::new(invoke_handler))
+}
@@ -1,32 +1,96 @@
 // Learn more about Tauri commands at https://tauri.app/develop/calling-rust/
+mod app_builder;
+mod window_state;
+
+use app_builder::{command_group, AppBuilder};
+use serde::Serialize;
+use tauri::{AppHandle, Emitter};
 use tauri::Manager;
 #[tauri::command]
 fn greet(name: &str) -> String {
     format!("Hello, {}! You've been greeted from Rust!", name)
 }
 
+#[derive(Clone, Serialize)]
+struct TaskProgress {
+    percentage: u8,
+    message: String,
+}
+
+/// Example of a long-running command that streams progress back to the
+/// frontend instead of blocking on a single return value.
+#[tauri::command]
+async fn run_task(app: AppHandle) {
+    tauri::async_runtime::spawn(async move {
+        for percentage in (0..=100).step_by(20) {
+            let progress = TaskProgress {
+                percentage,
+                message: format!("Working... {percentage}%"),
+            };
+            let _ = app.emit("task-progress", progress);
+            tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+        }
+        let _ = app.emit("task-done", ());
+    });
+}
+
+fn position_on_current_monitor(window: &tauri::WebviewWindow) {
+    let monitor = window
+        .current_monitor()
+        .ok()
+        .flatten()
+        .or_else(|| window.primary_monitor().ok().flatten());
+
+    if let Some(monitor) = monitor {
+        let work_area = monitor.work_area();
+        let _ = window.set_position(work_area.position);
+        let _ = window.set_size(work_area.size);
+    }
+}
+
+/// The splashscreen/main-window startup flow. [`app`] registers it as a
+/// setup hook, so it is just one of potentially several hooks run by
+/// [`AppBuilder`] rather than baked into a single hardcoded chain.
+fn splashscreen_setup(app: &mut tauri::App) -> Result<(), Box<dyn std::error::Error>> {
+    let splashscreen = app.get_webview_window("splashscreen");
+    let main = app.get_webview_window("main");
+
+    if let Some(main) = main.clone() {
+        let _ = main.hide();
+        window_state::persist_on_close(&main);
+    }
+
+    tauri::async_runtime::spawn(async move {
+        // Placeholder for real setup work (DB migrations, config load, etc).
+        tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+
+        if let Some(splashscreen) = splashscreen {
+            let _ = splashscreen.close();
+        }
+        if let Some(main) = main {
+            window_state::restore_or_fallback(&main, position_on_current_monitor);
+            let _ = main.show();
+        }
+    });
+
+    Ok(())
+}
+
+/// Builds the shared app configuration used by both the desktop and mobile
+/// entry points.
+fn app() -> AppBuilder {
+    let (commands, handler) = command_group(
+        &["greet", "run_task"],
+        tauri::generate_handler![greet, run_task],
+    );
+
+    AppBuilder::new()
+        .plugin(tauri_plugin_opener::init())
+        .commands(commands, handler)
+        .setup(splashscreen_setup)
+}
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
-    tauri::Builder::default()
-        .plugin(tauri_plugin_opener::init())
-        .invoke_handler(tauri::generate_handler![greet])
-        .setup(|app| {
-            if let Some(window) = app.get_webview_window("main") {
-                let monitor = window
-                    .current_monitor()
-                    .ok()
-                    .flatten()
-                    .or_else(|| window.primary_monitor().ok().flatten());
-
-                if let Some(monitor) = monitor {
-                    let work_area = monitor.work_area();
-                    let _ = window.set_position(work_area.position);
-                    let _ = window.set_size(work_area.size);
-                }
-                let _ = window.show();
-            }
-            Ok(())
-        })
-        .run(tauri::generate_context!())
-        .expect("error while running tauri application");
+    app().run(tauri::generate_context!());
 }
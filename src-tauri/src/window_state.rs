@@ -0,0 +1,97 @@
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use tauri::{AppHandle, Manager, WebviewWindow, WindowEvent};
+
+#[derive(Clone, Serialize, Deserialize)]
+struct WindowState {
+    x: i32,
+    y: i32,
+    width: u32,
+    height: u32,
+    maximized: bool,
+    fullscreen: bool,
+}
+
+fn state_file_path(app: &AppHandle, label: &str) -> Option<PathBuf> {
+    let dir = app.path().app_config_dir().ok()?;
+    Some(dir.join(format!("{label}.window-state.json")))
+}
+
+fn load_state(app: &AppHandle, label: &str) -> Option<WindowState> {
+    let path = state_file_path(app, label)?;
+    let contents = fs::read_to_string(path).ok()?;
+    serde_json::from_str(&contents).ok()
+}
+
+fn save_state(window: &WebviewWindow, state: &WindowState) {
+    let Some(path) = state_file_path(&window.app_handle().clone(), window.label()) else {
+        return;
+    };
+    if let Some(parent) = path.parent() {
+        let _ = fs::create_dir_all(parent);
+    }
+    if let Ok(json) = serde_json::to_string_pretty(state) {
+        let _ = fs::write(path, json);
+    }
+}
+
+/// Returns whether `(x, y)` falls within the bounds of at least one
+/// currently connected monitor.
+fn is_position_on_a_monitor(window: &WebviewWindow, x: i32, y: i32) -> bool {
+    let Ok(monitors) = window.available_monitors() else {
+        return false;
+    };
+    monitors.iter().any(|monitor| {
+        let pos = monitor.position();
+        let size = monitor.size();
+        x >= pos.x
+            && y >= pos.y
+            && x < pos.x + size.width as i32
+            && y < pos.y + size.height as i32
+    })
+}
+
+/// Positions and sizes `window` from disk if saved state exists and its
+/// top-left corner still lands on a connected monitor, otherwise falls back
+/// to `fallback`, which should apply the current monitor's work-area logic.
+pub fn restore_or_fallback(window: &WebviewWindow, fallback: impl FnOnce(&WebviewWindow)) {
+    let state = load_state(&window.app_handle().clone(), window.label())
+        .filter(|state| is_position_on_a_monitor(window, state.x, state.y));
+
+    let Some(state) = state else {
+        fallback(window);
+        return;
+    };
+
+    let _ = window.set_position(tauri::PhysicalPosition::new(state.x, state.y));
+    let _ = window.set_size(tauri::PhysicalSize::new(state.width, state.height));
+    let _ = window.set_maximized(state.maximized);
+    let _ = window.set_fullscreen(state.fullscreen);
+}
+
+/// Registers a close handler on `window` that persists its geometry so it
+/// can be restored by [`restore_or_fallback`] on the next launch.
+pub fn persist_on_close(window: &WebviewWindow) {
+    let window = window.clone();
+    window.clone().on_window_event(move |event| {
+        if let WindowEvent::CloseRequested { .. } = event {
+            let Ok(position) = window.outer_position() else {
+                return;
+            };
+            let Ok(size) = window.outer_size() else {
+                return;
+            };
+            let state = WindowState {
+                x: position.x,
+                y: position.y,
+                width: size.width,
+                height: size.height,
+                maximized: window.is_maximized().unwrap_or(false),
+                fullscreen: window.is_fullscreen().unwrap_or(false),
+            };
+            save_state(&window, &state);
+        }
+    });
+}